@@ -0,0 +1,108 @@
+use crate::procon::modulo::Finite;
+
+/// Precomputes `fact[0..=n]` and `inv_fact[0..=n]` mod `P` in O(n), giving O(1) `comb`/`perm`.
+pub struct Factorial<const P: i64> {
+    fact: Vec<Finite<P>>,
+    inv_fact: Vec<Finite<P>>,
+}
+
+impl<const P: i64> Factorial<P> {
+    /// Builds the table for `0..=n`. O(n) time.
+    pub fn new(n: usize) -> Self {
+        let mut fact = vec![Finite::from(1); n + 1];
+        for i in 1..=n {
+            fact[i] = fact[i - 1] * i as i64;
+        }
+
+        let mut inv_fact = vec![Finite::from(1); n + 1];
+        inv_fact[n] = fact[n].pow(P - 2);
+        for i in (0..n).rev() {
+            inv_fact[i] = inv_fact[i + 1] * (i as i64 + 1);
+        }
+
+        Factorial { fact, inv_fact }
+    }
+
+    pub fn fact(&self, n: usize) -> Finite<P> {
+        self.fact[n]
+    }
+
+    pub fn inv_fact(&self, n: usize) -> Finite<P> {
+        self.inv_fact[n]
+    }
+
+    /// `nCk`. Returns 0 if `k > n`.
+    pub fn comb(&self, n: usize, k: usize) -> Finite<P> {
+        if k > n {
+            return Finite::from(0);
+        }
+        self.fact[n] * self.inv_fact[k] * self.inv_fact[n - k]
+    }
+
+    /// `nPk`. Returns 0 if `k > n`.
+    pub fn perm(&self, n: usize, k: usize) -> Finite<P> {
+        if k > n {
+            return Finite::from(0);
+        }
+        self.fact[n] * self.inv_fact[n - k]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Factorial;
+    use crate::procon::modulo::Finite;
+
+    const P: i64 = 1_000_000_007;
+    type Fp = Finite<P>;
+
+    #[test]
+    fn test_fact() {
+        let f: Factorial<P> = Factorial::new(10);
+        assert_eq!(f.fact(0), 1.into());
+        assert_eq!(f.fact(1), 1.into());
+        assert_eq!(f.fact(5), 120.into());
+        assert_eq!(f.fact(5) * f.inv_fact(5), Fp::from(1));
+    }
+
+    #[test]
+    fn test_comb() {
+        let f: Factorial<P> = Factorial::new(30);
+        assert_eq!(f.comb(5, 2), 10.into());
+        assert_eq!(f.comb(30, 0), 1.into());
+        assert_eq!(f.comb(30, 30), 1.into());
+        assert_eq!(f.comb(5, 6), 0.into());
+    }
+
+    #[test]
+    fn test_perm() {
+        let f: Factorial<P> = Factorial::new(10);
+        assert_eq!(f.perm(5, 2), 20.into());
+        assert_eq!(f.perm(5, 0), 1.into());
+        assert_eq!(f.perm(5, 6), 0.into());
+    }
+
+    #[test]
+    fn test_comb_matches_pascal() {
+        let n = 40;
+        let f: Factorial<P> = Factorial::new(n);
+
+        let mut pascal = vec![vec![0_i64; n + 1]; n + 1];
+        for i in 0..=n {
+            pascal[i][0] = 1;
+            for j in 1..=i {
+                pascal[i][j] = if j == i {
+                    1
+                } else {
+                    pascal[i - 1][j - 1] + pascal[i - 1][j]
+                };
+            }
+        }
+
+        for (i, row) in pascal.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate().take(i + 1) {
+                assert_eq!(f.comb(i, j), value.into(), "comb({}, {})", i, j);
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/// Computes `gcd(a, b)`.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Extended Euclidean algorithm. Returns `(g, x, y)` with `g = gcd(a, m)` and `a * x + m * y = g`.
+pub fn ext_gcd(a: i64, m: i64) -> (i64, i64, i64) {
+    if m == 0 {
+        (a.abs(), a.signum(), 0)
+    } else {
+        let (g, x, y) = ext_gcd(m, a % m);
+        (g, y, x - (a / m) * y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ext_gcd, gcd};
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(5, 0), 5);
+    }
+
+    #[test]
+    fn test_ext_gcd() {
+        for a in -20_i64..20 {
+            for m in 1_i64..20 {
+                let (g, x, y) = ext_gcd(a, m);
+                assert_eq!(g, gcd(a, m), "gcd({}, {})", a, m);
+                assert_eq!(a * x + m * y, g, "{} * {} + {} * {} == {}", a, x, m, y, g);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ext_gcd_negative_a_mod_inv() {
+        use crate::procon::modulo::mod_inv;
+
+        // -5 is invertible mod 12 since gcd(-5, 12) == 1: 7 * -5 == -35 == 1 (mod 12).
+        assert_eq!(mod_inv(-5, 12), Some(7));
+    }
+}
@@ -2,11 +2,13 @@ pub mod binary_indexed_tree;
 pub mod binary_search;
 pub mod buckets;
 pub mod dijkstra;
+pub mod factorial;
 pub mod gcd;
 pub mod graph;
 pub mod grid_vec;
 pub mod iter_ext;
 pub mod modulo;
+pub mod ntt;
 pub mod ord_adapter;
 pub mod perm;
 pub mod prime;
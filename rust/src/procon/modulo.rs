@@ -0,0 +1,304 @@
+use std;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::*;
+
+use crate::procon::gcd::ext_gcd;
+
+/// Calculates `1/a mod m` via the extended Euclidean algorithm. Unlike `pow(a, m - 2)`, this
+/// works for any `m` (not necessarily prime). Returns `None` if `a` is not invertible mod `m`,
+/// i.e. `gcd(a, m) != 1`.
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = ext_gcd(a, m);
+    if g == 1 {
+        Some(x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+/// Calculates `x^n` mod `P`. O(log n) time.
+/// By Fermat's little theorem, `x^(-1) = pow::<P>(x, P - 2)` when `P` is prime.
+pub fn pow<const P: i64>(x: i64, n: i64) -> i64 {
+    let (mut x, mut y, mut n) = (x % P, 1_i64, n);
+    while n > 0 {
+        if n % 2 != 0 {
+            y = (y * x) % P;
+            n -= 1;
+        }
+
+        x = (x * x) % P;
+        n /= 2;
+    }
+    y
+}
+
+/// Calculates `1/a` for each `a` in `1..n`, mod `P`.
+/// Use `P = floor(P / k) * k + P % k` for proof.
+pub fn inv_dp<const P: i64>(n: usize) -> Vec<i64> {
+    let mut dp = vec![0; n];
+    if n >= 2 {
+        dp[1] = 1;
+        for i in 2..n {
+            let mut z = P - dp[(P % i as i64) as usize];
+            z %= P;
+            z *= P / i as i64;
+            z %= P;
+            dp[i] = z;
+        }
+    }
+    dp
+}
+
+/// Represents an element of the finite field modulo `P`.
+///
+/// `P` is expected to be prime so that `Div` (via Fermat's little theorem) is well-defined.
+/// Pick a modulus with e.g. `type Fp = Finite<998244353>;`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+pub struct Finite<const P: i64>(i64);
+
+impl<const P: i64> Finite<P> {
+    pub fn pow(self, e: i64) -> Self {
+        pow::<P>(self.0, e).into()
+    }
+
+    /// Computes `1/self` via the extended Euclidean algorithm, unlike `Div` (which uses Fermat's
+    /// little theorem and so requires `P` to be prime). Returns `None` if `self` is not
+    /// invertible mod `P`.
+    pub fn inv(self) -> Option<Self> {
+        mod_inv(self.0, P).map(Finite::from)
+    }
+}
+
+impl<const P: i64> Debug for Finite<P> {
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_fmt(format_args!("{:?}", self.0))
+    }
+}
+
+impl<const P: i64> Display for Finite<P> {
+    fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_fmt(format_args!("{}", self.0))
+    }
+}
+
+impl<const P: i64> From<i64> for Finite<P> {
+    fn from(value: i64) -> Self {
+        Finite((value % P + P) % P)
+    }
+}
+
+impl<const P: i64> From<Finite<P>> for i64 {
+    fn from(value: Finite<P>) -> Self {
+        value.0
+    }
+}
+
+// Generate binary operation traits.
+macro_rules! impl_binary_op_for_finite {
+    ($op_trait:ident, $op:ident, $assign_trait:ident, $assign:ident $(, $f:ident)*) => {
+        $(impl<const P: i64> $op_trait<Finite<P>> for Finite<P> {
+            type Output = Self;
+
+            fn $op(self, other: Self) -> Self {
+                Finite::from((self.0).$f(other.0))
+            }
+        })*
+
+        impl<const P: i64> $op_trait<i64> for Finite<P> {
+            type Output = Self;
+
+            fn $op(self, other: i64) -> Self {
+                self.$op(Finite::from(other))
+            }
+        }
+
+        impl<const P: i64> $assign_trait<Finite<P>> for Finite<P> {
+            fn $assign(&mut self, other: Self) {
+                *self = self.$op(other)
+            }
+        }
+
+        impl<const P: i64> $assign_trait<i64> for Finite<P> {
+            fn $assign(&mut self, other: i64) {
+                *self = self.$op(other)
+            }
+        }
+
+        // Reference-taking variants, so `&a + &b`, `a + &b`, and `&a * b` compile with the same
+        // semantics as the owned versions above (`Finite` is `Copy`, so these just dereference).
+        impl<const P: i64> $op_trait<&Finite<P>> for Finite<P> {
+            type Output = Self;
+
+            fn $op(self, other: &Self) -> Self {
+                self.$op(*other)
+            }
+        }
+
+        impl<const P: i64> $op_trait<Finite<P>> for &Finite<P> {
+            type Output = Finite<P>;
+
+            fn $op(self, other: Finite<P>) -> Finite<P> {
+                (*self).$op(other)
+            }
+        }
+
+        impl<const P: i64> $op_trait<&Finite<P>> for &Finite<P> {
+            type Output = Finite<P>;
+
+            fn $op(self, other: &Finite<P>) -> Finite<P> {
+                (*self).$op(*other)
+            }
+        }
+
+        impl<const P: i64> $assign_trait<&Finite<P>> for Finite<P> {
+            fn $assign(&mut self, other: &Self) {
+                *self = self.$op(*other)
+            }
+        }
+    };
+}
+
+impl_binary_op_for_finite! {Add, add, AddAssign, add_assign, add}
+impl_binary_op_for_finite! {Sub, sub, SubAssign, sub_assign, sub}
+impl_binary_op_for_finite! {Mul, mul, MulAssign, mul_assign, mul}
+impl_binary_op_for_finite! {Div, div, DivAssign, div_assign}
+
+impl<const P: i64> Div<Finite<P>> for Finite<P> {
+    type Output = Finite<P>;
+
+    fn div(self, other: Self) -> Self {
+        self * other.pow(P - 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{inv_dp, mod_inv, pow, Finite};
+    use std;
+
+    const P: i64 = 1_000_000_007;
+    type Fp = Finite<P>;
+
+    #[test]
+    fn test_pow_edges() {
+        assert_eq!(pow::<P>(0, 0), 1);
+        assert_eq!(pow::<P>(2, 0), 1);
+        assert_eq!(pow::<P>(3, 1), 3);
+        assert_eq!(pow::<P>(5, 6), (5 * 5 * 5) * (5 * 5 * 5));
+        assert_eq!(pow::<P>(std::i64::MAX % P, std::i64::MAX), 856225998);
+    }
+
+    #[test]
+    fn test_pow_small() {
+        for x in 0..100_i64 {
+            for n in 0..100_i64 {
+                let actual = pow::<P>(x, n);
+                let expected = (0..n).fold(1_i64, |acc, _| (acc * x) % P);
+                assert_eq!(actual, expected, "{}^{}", x, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inv_dp() {
+        let n = 10000;
+        let dp = inv_dp::<P>(n);
+        for i in 1..n {
+            let mut z = dp[i] * i as i64;
+            z %= P;
+            z += P;
+            z %= P;
+            assert_eq!(z, 1);
+        }
+    }
+
+    #[test]
+    fn test_finite() {
+        let x = Fp::from(P + 2);
+
+        // `from` should normalize the value.
+        assert_eq!(x.0, 2);
+
+        // Operations.
+        assert_eq!(x + 7, (2 + 7).into());
+        assert_eq!(x - 7, (P + (2 - 7)).into());
+        assert_eq!(x * 3, (2 * 3).into());
+        assert_eq!((x / 11) * 11, 2.into());
+
+        assert_eq!(x + Fp::from(7), (2 + 7).into());
+        assert_eq!(x - Fp::from(7), (P + (2 - 7)).into());
+        assert_eq!(x * Fp::from(3), (2 * 3).into());
+        assert_eq!((x / Fp::from(11)) * 11, 2.into());
+
+        let mut x = x;
+        x += 7;
+        assert_eq!(x, 9.into());
+        x -= 5;
+        assert_eq!(x, 4.into());
+        x *= 6;
+        assert_eq!(x, 24.into());
+        x /= 3;
+        assert_eq!(x, 8.into());
+    }
+
+    #[test]
+    fn test_finite_fmt() {
+        assert_eq!(format!("{:?}", Fp::from(2)), "2");
+        assert_eq!(format!("{}", Fp::from(2)), "2");
+    }
+
+    #[test]
+    fn test_other_modulus() {
+        type Fq = Finite<998244353>;
+        let x = Fq::from(998244353 + 5);
+        assert_eq!(x, 5.into());
+        assert_eq!((x / Fq::from(3)) * 3, x);
+    }
+
+    #[test]
+    fn test_mod_inv_prime() {
+        for a in 1..10_000_i64 {
+            let x = mod_inv(a, P).unwrap();
+            assert_eq!((a * x).rem_euclid(P), 1, "a = {}", a);
+        }
+    }
+
+    #[test]
+    fn test_mod_inv_composite() {
+        // 4 is invertible mod 9 (gcd(4, 9) == 1) but not mod 6 (gcd(4, 6) == 2).
+        let x = mod_inv(4, 9).unwrap();
+        assert_eq!((4 * x).rem_euclid(9), 1);
+        assert_eq!(mod_inv(4, 6), None);
+    }
+
+    #[test]
+    // The `&a op &b` forms are the point of the test (exercising the new ref-op impls), not
+    // accidental borrows clippy should suggest dropping.
+    #[allow(clippy::op_ref)]
+    fn test_finite_ref_ops() {
+        let a = Fp::from(7);
+        let b = Fp::from(3);
+
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(a + &b, a + b);
+        assert_eq!(&a + b, a + b);
+
+        assert_eq!(&a - &b, a - b);
+        assert_eq!(&a * &b, a * b);
+        assert_eq!(&a / &b, a / b);
+
+        let mut x = a;
+        x += &b;
+        assert_eq!(x, a + b);
+    }
+
+    #[test]
+    fn test_finite_inv() {
+        let x = Fp::from(12345);
+        assert_eq!(x.inv().unwrap() * x, Fp::from(1));
+
+        type Fq = Finite<12>;
+        assert_eq!(Fq::from(4).inv(), None);
+        assert_eq!(Fq::from(5).inv().unwrap() * Fq::from(5), Fq::from(1));
+    }
+}
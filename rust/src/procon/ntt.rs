@@ -0,0 +1,267 @@
+use crate::procon::modulo::{pow, Finite};
+
+/// NTT-friendly prime: `998244353 = 119 * 2^23 + 1`.
+const P: i64 = 998244353;
+
+/// A primitive root of `P`.
+const G: i64 = 3;
+
+/// An element of the field used by the NTT, modulo `P`.
+pub type Fp = Finite<P>;
+
+/// In-place Cooley-Tukey NTT (`invert == false`) or its inverse (`invert == true`), over the
+/// field mod `Q` with primitive root `R`. `a.len()` must be a power of two.
+fn transform<const Q: i64, const R: i64>(a: &mut [Finite<Q>], invert: bool) {
+    let n = a.len();
+    debug_assert!(n.is_power_of_two());
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while bit > 0 && j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        // omega = g^((Q - 1) / len), the root of unity of order `len`.
+        let omega = Finite::<Q>::from(R).pow((Q - 1) / len as i64);
+        let omega = if invert { omega.pow(Q - 2) } else { omega };
+
+        let mut i = 0;
+        while i < n {
+            let mut w = Finite::<Q>::from(1);
+            for k in 0..len / 2 {
+                let u = a[i + k];
+                let v = a[i + k + len / 2] * w;
+                a[i + k] = u + v;
+                a[i + k + len / 2] = u - v;
+                w *= omega;
+            }
+            i += len;
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = Finite::<Q>::from(n as i64).pow(Q - 2);
+        for x in a.iter_mut() {
+            *x *= n_inv;
+        }
+    }
+}
+
+/// Multiplies two polynomials (coefficient vectors mod `Q`, lowest degree first), over the
+/// NTT-friendly field mod `Q` with primitive root `R`.
+/// O((n + m) log(n + m)) time, where `n = a.len()`, `m = b.len()`.
+fn fps_mul_mod<const Q: i64, const R: i64>(
+    a: &[Finite<Q>],
+    b: &[Finite<Q>],
+) -> Vec<Finite<Q>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let need = a.len() + b.len() - 1;
+    let n = need.next_power_of_two();
+
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fa.resize(n, Finite::from(0));
+    fb.resize(n, Finite::from(0));
+
+    transform::<Q, R>(&mut fa, false);
+    transform::<Q, R>(&mut fb, false);
+
+    for i in 0..n {
+        fa[i] *= fb[i];
+    }
+
+    transform::<Q, R>(&mut fa, true);
+    fa.truncate(need);
+    fa
+}
+
+/// Multiplies two polynomials (given as coefficient vectors, lowest degree first) mod `P`.
+/// O((n + m) log(n + m)) time, where `n = a.len()`, `m = b.len()`.
+pub fn fps_mul(a: &[Fp], b: &[Fp]) -> Vec<Fp> {
+    fps_mul_mod::<P, G>(a, b)
+}
+
+// Three pairwise-coprime NTT-friendly primes. Their product is about 5.95 * 10^25, which bounds
+// the true convolution coefficients that `any_mod_fps_mul` can reconstruct exactly.
+const CRT_P1: i64 = 167772161; // = 5 * 2^25 + 1, primitive root 3
+const CRT_G1: i64 = 3;
+const CRT_P2: i64 = 469762049; // = 7 * 2^26 + 1, primitive root 3
+const CRT_G2: i64 = 3;
+const CRT_P3: i64 = 754974721; // = 45 * 2^24 + 1, primitive root 11
+const CRT_G3: i64 = 11;
+
+/// Combines `x ≡ r1 (mod m1)`, `x ≡ r2 (mod M2)` into `(x, m1 * M2)` with `x ≡ r (mod m1 * M2)`,
+/// assuming `m1` and `M2` are coprime and `M2` is prime (so Fermat's little theorem gives the
+/// inverse of `m1` mod `M2`).
+fn crt_combine<const M2: i64>(r1: i128, m1: i128, r2: i64) -> (i128, i128) {
+    let m1_mod_m2 = m1.rem_euclid(M2 as i128) as i64;
+    let inv_m1 = pow::<M2>(m1_mod_m2, M2 - 2);
+
+    let t = ((r2 as i128 - r1).rem_euclid(M2 as i128) * inv_m1 as i128).rem_euclid(M2 as i128);
+
+    let r = r1 + m1 * t;
+    let m = m1 * M2 as i128;
+    (r, m)
+}
+
+/// Converts raw integer coefficients into elements mod `Q`.
+fn to_fp<const Q: i64>(xs: &[i64]) -> Vec<Finite<Q>> {
+    xs.iter().map(|&x| Finite::from(x)).collect()
+}
+
+/// Multiplies two integer-coefficient polynomials modulo an arbitrary `M` (not necessarily
+/// NTT-friendly, or even prime): it convolves under three NTT-friendly primes, then Garner's
+/// algorithm reconstructs the integer coefficient from the three residues before it is reduced
+/// mod `M`. Coefficients of `a`/`b` may be negative, as can the true convolution value; the CRT
+/// residue is re-centered into `(-prod/2, prod/2]` before reducing mod `M`, where `prod` is the
+/// product of the three primes (about 5.95 * 10^25). A true coefficient outside that range is
+/// not recovered exactly, and the result for it is wrong.
+pub fn any_mod_fps_mul<const M: i64>(a: &[i64], b: &[i64]) -> Vec<Finite<M>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let r1 = fps_mul_mod::<CRT_P1, CRT_G1>(&to_fp(a), &to_fp(b));
+    let r2 = fps_mul_mod::<CRT_P2, CRT_G2>(&to_fp(a), &to_fp(b));
+    let r3 = fps_mul_mod::<CRT_P3, CRT_G3>(&to_fp(a), &to_fp(b));
+
+    (0..r1.len())
+        .map(|i| {
+            let r1i: i64 = r1[i].into();
+            let r2i: i64 = r2[i].into();
+            let r3i: i64 = r3[i].into();
+
+            let (x12, m12) = crt_combine::<CRT_P2>(r1i as i128, CRT_P1 as i128, r2i);
+            let (x123, m123) = crt_combine::<CRT_P3>(x12, m12, r3i);
+
+            // `x123` is the residue in `[0, m123)`; re-center it to recover the true (possibly
+            // negative) convolution value before reducing mod `M`.
+            let signed = if x123 > m123 / 2 { x123 - m123 } else { x123 };
+
+            Finite::from(signed.rem_euclid(M as i128) as i64)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{any_mod_fps_mul, fps_mul, Fp};
+    use crate::procon::modulo::Finite;
+
+    fn naive_mul(a: &[Fp], b: &[Fp]) -> Vec<Fp> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let mut c = vec![Fp::from(0); a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] += x * y;
+            }
+        }
+        c
+    }
+
+    fn fp_vec(xs: &[i64]) -> Vec<Fp> {
+        xs.iter().map(|&x| Fp::from(x)).collect()
+    }
+
+    #[test]
+    fn test_fps_mul_matches_naive() {
+        let a = fp_vec(&[1, 2, 3]);
+        let b = fp_vec(&[4, 5, 6, 7]);
+        assert_eq!(fps_mul(&a, &b), naive_mul(&a, &b));
+    }
+
+    #[test]
+    fn test_fps_mul_identity() {
+        let a = fp_vec(&[1]);
+        let b = fp_vec(&[10, 20, 30]);
+        assert_eq!(fps_mul(&a, &b), b);
+    }
+
+    #[test]
+    fn test_fps_mul_empty() {
+        let a: Vec<Fp> = vec![];
+        let b = fp_vec(&[1, 2, 3]);
+        assert_eq!(fps_mul(&a, &b), Vec::<Fp>::new());
+    }
+
+    #[test]
+    fn test_fps_mul_large() {
+        let a: Vec<Fp> = (1..=200).map(Fp::from).collect();
+        let b: Vec<Fp> = (1..=150).map(Fp::from).collect();
+        assert_eq!(fps_mul(&a, &b), naive_mul(&a, &b));
+    }
+
+    fn naive_mul_any_mod<const M: i64>(a: &[i64], b: &[i64]) -> Vec<Finite<M>> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let mut c = vec![0_i128; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] += x as i128 * y as i128;
+            }
+        }
+        c.into_iter()
+            .map(|x| Finite::from(x.rem_euclid(M as i128) as i64))
+            .collect()
+    }
+
+    #[test]
+    fn test_any_mod_fps_mul_non_ntt_friendly_modulus() {
+        const M: i64 = 1_000_000_007;
+        let a: Vec<i64> = (1..=50).collect();
+        let b: Vec<i64> = (1..=40).collect();
+        assert_eq!(
+            any_mod_fps_mul::<M>(&a, &b),
+            naive_mul_any_mod::<M>(&a, &b)
+        );
+    }
+
+    #[test]
+    fn test_any_mod_fps_mul_large_coefficients() {
+        const M: i64 = 998244353;
+        let a: Vec<i64> = vec![1_000_000_000; 300];
+        let b: Vec<i64> = vec![999_999_999; 300];
+        assert_eq!(
+            any_mod_fps_mul::<M>(&a, &b),
+            naive_mul_any_mod::<M>(&a, &b)
+        );
+    }
+
+    #[test]
+    fn test_any_mod_fps_mul_negative_coefficients() {
+        const M: i64 = 5;
+        assert_eq!(any_mod_fps_mul::<M>(&[-1], &[1]), vec![Finite::from(-1)]);
+
+        let a: Vec<i64> = vec![1, -2, 3, -4, 5];
+        let b: Vec<i64> = vec![-5, 4, -3, 2, -1];
+        assert_eq!(
+            any_mod_fps_mul::<M>(&a, &b),
+            naive_mul_any_mod::<M>(&a, &b)
+        );
+    }
+
+    #[test]
+    fn test_any_mod_fps_mul_empty() {
+        const M: i64 = 1_000_000_007;
+        let a: Vec<i64> = vec![];
+        let b: Vec<i64> = vec![1, 2, 3];
+        assert_eq!(any_mod_fps_mul::<M>(&a, &b), Vec::<Finite<M>>::new());
+    }
+}